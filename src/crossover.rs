@@ -0,0 +1,89 @@
+//! Genome recombination ("crossover") operators.
+//!
+//! Neither operator constructs its own offspring - `child` is supplied by the caller and
+//! written into gene-by-gene through [get_param](../trait.ParamHolder.html#method.get_param),
+//! the same interface [mutation](../mutation/index.html) modifies genes through. This works
+//! for any `ParamHolder`, including nested `ParamSet2d`/`ParamSet3d`.
+//!
+//! # Examples
+//! ```
+//! # use genotype::*;
+//! # use genotype::crossover::single_point;
+//! struct Weight(Param);
+//!
+//! impl RangedParam for Weight {
+//!     fn range(&self) -> (Param, Param) { (40.0, 100.0) }
+//!     fn get(&self) -> Param { self.0 }
+//!     fn get_mut(&mut self) -> &mut Param { &mut self.0 }
+//! }
+//!
+//! struct Human { weight: Weight }
+//!
+//! impl ParamHolder for Human {
+//!     fn param_count(&self) -> usize { 1 }
+//!     fn get_param(&mut self, index: usize) -> &mut RangedParam {
+//!         match index {
+//!             0 => &mut self.weight,
+//!             _ => panic!("Bad index"),
+//!         }
+//!     }
+//! }
+//!
+//! let mut a = Human { weight: Weight(0.2) };
+//! let mut b = Human { weight: Weight(0.8) };
+//! let mut child = Human { weight: Weight(0.0) };
+//!
+//! single_point(&mut a, &mut b, &mut child, &mut rand::thread_rng());
+//! ```
+
+use super::*;
+use rand::distributions::{Bernoulli, Distribution};
+use rand::Rng;
+
+/// Performs single-point crossover: copies parent `a`'s genes `[0, c)` and parent `b`'s genes
+/// `[c, n)` into `child`, where the cut point `c` is chosen uniformly at random from `[1, n)`.
+///
+/// If there is only one gene there is no valid cut point, so `child` simply takes it from `a`.
+///
+/// # Panics
+/// If `a`, `b` and `child` don't all report the same
+/// [param_count](../trait.ParamHolder.html#method.param_count).
+pub fn single_point<F: Float, P: ParamHolder<F>, R: Rng>(
+    a: &mut P,
+    b: &mut P,
+    child: &mut P,
+    rng: &mut R,
+) {
+    let n = a.param_count();
+    assert_eq!(n, b.param_count(), "parents must have the same param_count()");
+    assert_eq!(n, child.param_count(), "child must have the same param_count()");
+
+    let cut = if n > 1 { rng.gen_range(1..n) } else { n };
+    for i in 0..n {
+        let val = if i < cut { a.get_param(i).get() } else { b.get_param(i).get() };
+        *child.get_param(i).get_mut() = val;
+    }
+}
+
+/// Performs uniform crossover: for each gene, copies from parent `a` or parent `b` into
+/// `child` with equal probability.
+///
+/// # Panics
+/// If `a`, `b` and `child` don't all report the same
+/// [param_count](../trait.ParamHolder.html#method.param_count).
+pub fn uniform<F: Float, P: ParamHolder<F>, R: Rng>(
+    a: &mut P,
+    b: &mut P,
+    child: &mut P,
+    rng: &mut R,
+) {
+    let n = a.param_count();
+    assert_eq!(n, b.param_count(), "parents must have the same param_count()");
+    assert_eq!(n, child.param_count(), "child must have the same param_count()");
+
+    let coin = Bernoulli::new(0.5).expect("0.5 is always a valid probability");
+    for i in 0..n {
+        let val = if coin.sample(rng) { a.get_param(i).get() } else { b.get_param(i).get() };
+        *child.get_param(i).get_mut() = val;
+    }
+}
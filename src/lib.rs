@@ -82,6 +82,10 @@ println!("mutated shape: {:?}", shape);
 
 use std::ops::AddAssign;
 
+extern crate num_traits;
+extern crate rand;
+extern crate rand_distr;
+
 #[cfg(feature = "serialize")]
 extern crate serde;
 
@@ -89,11 +93,29 @@ extern crate serde;
 #[cfg(feature = "serialize")]
 extern crate serde_derive;
 
+pub mod crossover;
 pub mod mutation;
 pub mod param_set;
+pub mod selection;
 
 /// The type of a single gene.
-pub type Param = f64; // TODO replace this with a generic parameter?
+pub type Param = f64;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// The float types usable as a gene's underlying value. Sealed against implementations
+/// outside this crate - only `f32` and `f64` are supported.
+///
+/// Choose `f32` for memory-dense populations, or `f64` (the default, see [Param](type.Param.html))
+/// where precision matters more than memory.
+pub trait Float: num_traits::Float + sealed::Sealed {}
+
+impl Float for f32 {}
+impl Float for f64 {}
 
 /** An entity with multiple parameters, i.e. a chromosone.
 # Examples
@@ -145,14 +167,45 @@ impl ParamHolder for Human {
 }
 ```
 */
-pub trait ParamHolder {
+pub trait ParamHolder<F: Float = Param> {
     /// The number of parameters/genes on this chromosone.
     fn param_count(&self) -> usize;
 
     /// Returns a mutable reference to the gene at the given index.
     /// # Panics
     /// If `index >= self.param_count()`
-    fn get_param(&mut self, index: usize) -> &mut RangedParam;
+    fn get_param(&mut self, index: usize) -> &mut RangedParam<F>;
+
+    /// Dumps the unscaled value of every gene into a flat `Vec`, in the order used by
+    /// [get_param](#method.get_param). Combined with [load_genome](#method.load_genome), this
+    /// gives a clean path to persist and restore an entire individual's chromosone via serde,
+    /// e.g. for checkpointing a population between runs.
+    #[cfg(feature = "serialize")]
+    fn to_genome(&mut self) -> Vec<F> {
+        (0..self.param_count())
+            .map(|i| self.get_param(i).get())
+            .collect()
+    }
+
+    /// Loads every gene's unscaled value back from a flat slice produced by
+    /// [to_genome](#method.to_genome), clamping each value to `[0, 1]`.
+    ///
+    /// Named `load_genome` rather than `from_genome` since it takes `&mut self` - `get_param`'s
+    /// by-reference design means genes can only be written into an existing holder, not built
+    /// fresh from a slice.
+    /// # Panics
+    /// If `genome.len() != self.param_count()`
+    #[cfg(feature = "serialize")]
+    fn load_genome(&mut self, genome: &[F]) {
+        assert_eq!(
+            genome.len(),
+            self.param_count(),
+            "genome length does not match param_count()"
+        );
+        for (i, &val) in genome.iter().enumerate() {
+            *self.get_param(i).get_mut() = val.max(F::zero()).min(F::one());
+        }
+    }
 }
 
 /** Access to a gene's scaled value, i.e. the phenotype.
@@ -187,7 +240,7 @@ assert_eq!(weight.get_scaled(), 73.0);
 # }
 ```
 */
-pub trait RangedParam {
+pub trait RangedParam<F: Float = Param> {
     /** The range of allowed values, in the form `(min, max).`
     # Examples
     The value in phenotype space remains between 0 and 1 (default implementation):
@@ -212,31 +265,31 @@ pub trait RangedParam {
     # }
     ```
     */
-    fn range(&self) -> (Param, Param) {
-        (0.0, 1.0) // unscaled
+    fn range(&self) -> (F, F) {
+        (F::zero(), F::one()) // unscaled
     }
 
     /// Returns the *unscaled* parameter value.
-    fn get(&self) -> Param;
+    fn get(&self) -> F;
 
     /// Returns a mutable reference to the raw parameter value.
-    fn get_mut(&mut self) -> &mut Param;
+    fn get_mut(&mut self) -> &mut F;
 
     /// Returns the parameter value scaled to the range returned by [range](#method.range) i.e. the gene expressed in the phenotype.
-    fn get_scaled(&self) -> Param {
+    fn get_scaled(&self) -> F {
         let (min, max) = self.range();
         (max - min) * self.get() + min
     }
 }
 
-impl<'a> AddAssign<Param> for &'a mut RangedParam {
-    fn add_assign(&mut self, rhs: Param) {
+impl<'a, F: Float> AddAssign<F> for &'a mut RangedParam<F> {
+    fn add_assign(&mut self, rhs: F) {
         let clamped = {
             let val = *self.get_mut() + rhs;
-            if val < 0.0 {
-                0.0
-            } else if val > 1.0 {
-                1.0
+            if val < F::zero() {
+                F::zero()
+            } else if val > F::one() {
+                F::one()
             } else {
                 val
             }
@@ -256,7 +309,7 @@ macro_rules! assert_feq {
 
 #[cfg(test)]
 mod tests {
-    use super::{mutation::*, param_set::*, *};
+    use super::{crossover::*, mutation::*, param_set::*, selection::*, *};
     use std::cell::RefCell;
     use std::rc::Rc;
 
@@ -322,6 +375,140 @@ mod tests {
         assert_feq!(holder.borrow().x.get_scaled(), 20.0);
     }
 
+    #[test]
+    fn test_mutate_with_rate() {
+        let holder = Rc::new(RefCell::new(TestHolder { x: TestParam(0.0) }));
+
+        // rate of 0.0 never mutates
+        mutate_with_rate(
+            holder.clone(),
+            &mut ConstGen { 0: 0.5 },
+            &mut rand::thread_rng(),
+            0.0,
+        )
+        .unwrap();
+        assert_feq!(holder.borrow().x.get_scaled(), 0.0);
+
+        // rate of 1.0 always mutates, same as mutate()
+        mutate_with_rate(
+            holder.clone(),
+            &mut ConstGen { 0: 0.5 },
+            &mut rand::thread_rng(),
+            1.0,
+        )
+        .unwrap();
+        assert_feq!(holder.borrow().x.get_scaled(), 10.0);
+    }
+
+    #[test]
+    fn test_mutate_with_rate_invalid() {
+        let holder = Rc::new(RefCell::new(TestHolder { x: TestParam(0.0) }));
+        assert!(mutate_with_rate(
+            holder.clone(),
+            &mut ConstGen { 0: 0.5 },
+            &mut rand::thread_rng(),
+            1.5,
+        )
+        .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn test_genome_roundtrip() {
+        let holder = Rc::new(RefCell::new(TestHolder { x: TestParam(0.25) }));
+
+        let genome = holder.borrow_mut().to_genome();
+        assert_eq!(genome, vec![0.25]);
+
+        holder.borrow_mut().load_genome(&[0.75]);
+        assert_feq!(holder.borrow().x.get_scaled(), 15.0);
+
+        // out of range values are clamped
+        holder.borrow_mut().load_genome(&[1.5]);
+        assert_feq!(holder.borrow().x.get_scaled(), 20.0);
+    }
+
+    #[test]
+    fn test_roulette_select() {
+        let population = vec![
+            Rc::new(RefCell::new(0)),
+            Rc::new(RefCell::new(1)),
+            Rc::new(RefCell::new(2)),
+        ];
+        let fitnesses = [0.0, 10.0, 0.0];
+        let mut rng = rand::thread_rng();
+
+        // the only individual with non-zero fitness is always chosen
+        let chosen = roulette_select(&population, &fitnesses, &mut rng).unwrap();
+        assert_eq!(*chosen.borrow(), 1);
+
+        let parents = select_n(&population, &fitnesses, 5, &mut rng).unwrap();
+        assert_eq!(parents.len(), 5);
+        assert!(parents.iter().all(|p| *p.borrow() == 1));
+    }
+
+    #[test]
+    fn test_roulette_select_all_zero() {
+        let population = vec![Rc::new(RefCell::new(0)), Rc::new(RefCell::new(1))];
+        let fitnesses = [0.0, 0.0];
+        assert!(roulette_select(&population, &fitnesses, &mut rand::thread_rng()).is_err());
+    }
+
+    #[test]
+    fn test_single_point_crossover() {
+        let mut a = TestHolder { x: TestParam(0.2) };
+        let mut b = TestHolder { x: TestParam(0.8) };
+        let mut child = TestHolder { x: TestParam(0.0) };
+
+        // only one gene, so there's no valid cut point and the child always takes from `a`
+        single_point(&mut a, &mut b, &mut child, &mut rand::thread_rng());
+        assert_feq!(child.x.get_scaled(), 4.0);
+    }
+
+    #[test]
+    fn test_uniform_crossover() {
+        let mut a = TestHolder { x: TestParam(0.2) };
+        let mut b = TestHolder { x: TestParam(0.8) };
+        let mut child = TestHolder { x: TestParam(0.0) };
+
+        uniform(&mut a, &mut b, &mut child, &mut rand::thread_rng());
+        assert!(child.x.get() == 0.2 || child.x.get() == 0.8);
+    }
+
+    struct TestParamF32(f32);
+
+    impl RangedParam<f32> for TestParamF32 {
+        fn range(&self) -> (f32, f32) {
+            (0.0, 20.0)
+        }
+
+        fn get(&self) -> f32 {
+            self.0
+        }
+
+        fn get_mut(&mut self) -> &mut f32 {
+            &mut self.0
+        }
+    }
+
+    struct ConstGenF32(f32);
+
+    impl MutationGen<f32> for ConstGenF32 {
+        fn gen(&mut self) -> f32 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_mutate_f32() {
+        let mut param = TestParamF32(0.0);
+        {
+            let mut p: &mut RangedParam<f32> = &mut param;
+            p += ConstGenF32(0.5).gen();
+        }
+        assert_feq!(param.get_scaled(), 10.0);
+    }
+
     #[derive(Debug)]
     struct Pos(Param);
 
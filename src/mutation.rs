@@ -63,27 +63,139 @@ assert!((human.borrow().weight.get_scaled() - 70.0) < 0.00001);
 */
 
 use super::*;
+use rand::distributions::Bernoulli;
+use rand::rngs::ThreadRng;
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
 use std::cell::RefCell;
 use std::rc::Rc;
 
 /// Produces values to add to an unscaled gene value.
 ///
 /// Keep in mind that the unscaled value is clamped between 0.0 and 1.0.
-pub trait MutationGen {
+pub trait MutationGen<F: Float = Param> {
     /// Returns a value that is added to an unscaled gene.
-    fn gen(&mut self) -> Param;
+    fn gen(&mut self) -> F;
 }
 
 /// Mutates the given `ParamHolder` with the given `MutationGen` by iterating through all genes and
 /// adding to each the result of calling the mutation generator.
 ///
+/// Equivalent to calling [mutate_with_rate](fn.mutate_with_rate.html) with a `rate` of `1.0`,
+/// i.e. every gene is mutated.
+///
 /// See [examples](index.html#example).
-pub fn mutate<P: ParamHolder, MG: MutationGen>(param_holder: Rc<RefCell<P>>, mut_gen: &mut MG) {
+pub fn mutate<F: Float, P: ParamHolder<F>, MG: MutationGen<F>>(
+    param_holder: Rc<RefCell<P>>,
+    mut_gen: &mut MG,
+) {
+    mutate_with_rate(param_holder, mut_gen, &mut rand::thread_rng(), F::one())
+        .expect("a rate of 1.0 is always valid");
+}
+
+/// Mutates the given `ParamHolder` with the given `MutationGen`, applying the mutation to each
+/// gene independently with probability `rate`, rather than to every gene unconditionally like
+/// [mutate](fn.mutate.html) does.
+///
+/// # Errors
+/// Returns an error if `rate` is not a valid probability (NaN or outside `[0, 1]`), matching the
+/// restriction imposed by `rand`'s `Bernoulli` constructor.
+pub fn mutate_with_rate<F: Float, P: ParamHolder<F>, MG: MutationGen<F>, R: Rng>(
+    param_holder: Rc<RefCell<P>>,
+    mut_gen: &mut MG,
+    rng: &mut R,
+    rate: F,
+) -> Result<(), rand::distributions::BernoulliError> {
+    let trial = Bernoulli::new(rate.to_f64().expect("rate must be representable as f64"))?;
     let n = param_holder.borrow().param_count();
 
     for i in 0..n {
-        let mut holder = param_holder.borrow_mut();
-        let mut p: &mut RangedParam = holder.get_param(i);
-        p += mut_gen.gen();
+        if trial.sample(rng) {
+            let mut holder = param_holder.borrow_mut();
+            let mut p: &mut RangedParam<F> = holder.get_param(i);
+            p += mut_gen.gen();
+        }
+    }
+
+    Ok(())
+}
+
+/// A [MutationGen](trait.MutationGen.html) that returns a value drawn uniformly from
+/// `[low, high)` each time it is called.
+///
+/// # Examples
+/// ```
+/// # use genotype::mutation::{MutationGen, UniformMutation};
+/// let mut gen = UniformMutation::new(-0.1, 0.1);
+/// let _ = gen.gen();
+/// ```
+pub struct UniformMutation<R: Rng = ThreadRng> {
+    low: Param,
+    high: Param,
+    rng: R,
+}
+
+impl UniformMutation<ThreadRng> {
+    /// Creates a new `UniformMutation` backed by the thread-local RNG.
+    /// # Panics
+    /// If `low >= high`.
+    pub fn new(low: Param, high: Param) -> Self {
+        Self::with_rng(low, high, rand::thread_rng())
+    }
+}
+
+impl<R: Rng> UniformMutation<R> {
+    /// Creates a new `UniformMutation` backed by the given RNG.
+    /// # Panics
+    /// If `low >= high`.
+    pub fn with_rng(low: Param, high: Param, rng: R) -> Self {
+        assert!(low < high, "low must be less than high");
+        UniformMutation { low, high, rng }
+    }
+}
+
+impl<R: Rng> MutationGen<Param> for UniformMutation<R> {
+    fn gen(&mut self) -> Param {
+        self.rng.gen_range(self.low..self.high)
+    }
+}
+
+/// A [MutationGen](trait.MutationGen.html) that returns a zero-mean, Gaussian-distributed
+/// value with the given standard deviation each time it is called, so perturbations cluster
+/// near zero with occasional large jumps.
+///
+/// # Examples
+/// ```
+/// # use genotype::mutation::{MutationGen, GaussianMutation};
+/// let mut gen = GaussianMutation::new(0.05);
+/// let _ = gen.gen();
+/// ```
+pub struct GaussianMutation<R: Rng = ThreadRng> {
+    normal: Normal<Param>,
+    rng: R,
+}
+
+impl GaussianMutation<ThreadRng> {
+    /// Creates a new `GaussianMutation` backed by the thread-local RNG.
+    pub fn new(sigma: Param) -> Self {
+        Self::with_rng(sigma, rand::thread_rng())
+    }
+}
+
+impl<R: Rng> GaussianMutation<R> {
+    /// Creates a new `GaussianMutation` backed by the given RNG.
+    /// # Panics
+    /// If `sigma` is not finite (NaN or infinite).
+    pub fn with_rng(sigma: Param, rng: R) -> Self {
+        GaussianMutation {
+            normal: Normal::new(0.0, sigma).expect("sigma must be finite"),
+            rng,
+        }
+    }
+}
+
+impl<R: Rng> MutationGen<Param> for GaussianMutation<R> {
+    fn gen(&mut self) -> Param {
+        self.normal.sample(&mut self.rng)
     }
 }
@@ -51,37 +51,42 @@ assert!((z - 7.5).abs() < 0.00001);
 
 */
 use super::*;
+use std::marker::PhantomData;
 
 /// Represents a collection of related parameters.
-pub trait ParamSet<P: RangedParam>: ParamHolder {}
+pub trait ParamSet<F: Float, P: RangedParam<F>>: ParamHolder<F> {}
 
 /// A 3D parameter set containing x, y and z fields.
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone)]
-pub struct ParamSet3d<P: RangedParam> {
+pub struct ParamSet3d<P: RangedParam<F>, F: Float = Param> {
     pub x: P,
     pub y: P,
     pub z: P,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    _float: PhantomData<F>,
 }
 
 /// A 2D parameter set containing x and y.
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone)]
-pub struct ParamSet2d<P: RangedParam> {
+pub struct ParamSet2d<P: RangedParam<F>, F: Float = Param> {
     pub x: P,
     pub y: P,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    _float: PhantomData<F>,
 }
 
-impl<P: RangedParam> ParamSet<P> for ParamSet3d<P> {}
+impl<F: Float, P: RangedParam<F>> ParamSet<F, P> for ParamSet3d<P, F> {}
 
-impl<P: RangedParam> ParamSet<P> for ParamSet2d<P> {}
+impl<F: Float, P: RangedParam<F>> ParamSet<F, P> for ParamSet2d<P, F> {}
 
-impl<P: RangedParam> ParamHolder for ParamSet3d<P> {
+impl<F: Float, P: RangedParam<F>> ParamHolder<F> for ParamSet3d<P, F> {
     fn param_count(&self) -> usize {
         3
     }
 
-    fn get_param(&mut self, index: usize) -> &mut RangedParam {
+    fn get_param(&mut self, index: usize) -> &mut RangedParam<F> {
         match index % 3 {
             0 => &mut self.x,
             1 => &mut self.y,
@@ -91,12 +96,12 @@ impl<P: RangedParam> ParamHolder for ParamSet3d<P> {
     }
 }
 
-impl<P: RangedParam> ParamHolder for ParamSet2d<P> {
+impl<F: Float, P: RangedParam<F>> ParamHolder<F> for ParamSet2d<P, F> {
     fn param_count(&self) -> usize {
         2
     }
 
-    fn get_param(&mut self, index: usize) -> &mut RangedParam {
+    fn get_param(&mut self, index: usize) -> &mut RangedParam<F> {
         match index % 2 {
             0 => &mut self.x,
             1 => &mut self.y,
@@ -105,14 +110,19 @@ impl<P: RangedParam> ParamHolder for ParamSet2d<P> {
     }
 }
 
-impl<P: RangedParam> ParamSet3d<P> {
+impl<F: Float, P: RangedParam<F>> ParamSet3d<P, F> {
     /// Creates a new parameter set with the given values.
     pub fn new(x: P, y: P, z: P) -> Self {
-        Self { x, y, z }
+        Self {
+            x,
+            y,
+            z,
+            _float: PhantomData,
+        }
     }
 
     /// Returns a tuple that contains each parameter, scaled with [get_scaled](../trait.RangedParam.html#method.get_scaled)
-    pub fn components_scaled(&self) -> (Param, Param, Param) {
+    pub fn components_scaled(&self) -> (F, F, F) {
         (
             self.x.get_scaled(),
             self.y.get_scaled(),
@@ -121,14 +131,18 @@ impl<P: RangedParam> ParamSet3d<P> {
     }
 }
 
-impl<P: RangedParam> ParamSet2d<P> {
+impl<F: Float, P: RangedParam<F>> ParamSet2d<P, F> {
     /// Creates a new parameter set with the given values.
     pub fn new(x: P, y: P) -> Self {
-        Self { x, y }
+        Self {
+            x,
+            y,
+            _float: PhantomData,
+        }
     }
 
     /// Returns a tuple that contains each parameter, scaled with [get_scaled](../trait.RangedParam.html#method.get_scaled)
-    pub fn components_scaled(&self) -> (Param, Param) {
+    pub fn components_scaled(&self) -> (F, F) {
         (self.x.get_scaled(), self.y.get_scaled())
     }
 }
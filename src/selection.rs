@@ -0,0 +1,74 @@
+//! Fitness-proportionate ("roulette wheel") selection.
+//!
+//! # Examples
+//! ```
+//! # use std::rc::Rc;
+//! # use std::cell::RefCell;
+//! use genotype::selection::roulette_select;
+//!
+//! let population = vec![
+//!     Rc::new(RefCell::new("a")),
+//!     Rc::new(RefCell::new("b")),
+//!     Rc::new(RefCell::new("c")),
+//! ];
+//! let fitnesses = [1.0, 0.0, 3.0];
+//! let mut rng = rand::thread_rng();
+//!
+//! let chosen = roulette_select(&population, &fitnesses, &mut rng).unwrap();
+//! println!("chosen: {}", chosen.borrow());
+//! ```
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Selects a single individual from `population` with probability proportional to its
+/// fitness, i.e. fitness-proportionate ("roulette wheel") selection.
+///
+/// # Errors
+/// Returns an error if `fitnesses` is empty, all zero, or contains a negative or invalid
+/// weight, matching the restriction imposed by `rand`'s `WeightedIndex`.
+///
+/// # Panics
+/// If `fitnesses.len() != population.len()`
+pub fn roulette_select<P, R: Rng>(
+    population: &[Rc<RefCell<P>>],
+    fitnesses: &[f64],
+    rng: &mut R,
+) -> Result<Rc<RefCell<P>>, rand::distributions::WeightedError> {
+    assert_eq!(
+        population.len(),
+        fitnesses.len(),
+        "fitnesses.len() must match population.len()"
+    );
+
+    let dist = WeightedIndex::new(fitnesses)?;
+    Ok(population[dist.sample(rng)].clone())
+}
+
+/// Selects `n` parents from `population` via [roulette_select](fn.roulette_select.html),
+/// suitable for breeding a new generation.
+///
+/// # Errors
+/// See [roulette_select](fn.roulette_select.html).
+///
+/// # Panics
+/// If `fitnesses.len() != population.len()`
+pub fn select_n<P, R: Rng>(
+    population: &[Rc<RefCell<P>>],
+    fitnesses: &[f64],
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<Rc<RefCell<P>>>, rand::distributions::WeightedError> {
+    assert_eq!(
+        population.len(),
+        fitnesses.len(),
+        "fitnesses.len() must match population.len()"
+    );
+
+    let dist = WeightedIndex::new(fitnesses)?;
+    Ok((0..n)
+        .map(|_| population[dist.sample(rng)].clone())
+        .collect())
+}